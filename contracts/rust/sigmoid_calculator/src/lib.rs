@@ -23,21 +23,81 @@ mod fixed_point {
         U256::from(value) * SCALE_U256
     }
 
+    /// Compute `floor(a * b / denom)` at full 512-bit precision, so the
+    /// intermediate product never overflows `U256` even when `a` and `b`
+    /// are both close to the 256-bit limit.
+    ///
+    /// This is the FullMath technique (as used by Uniswap V3): recover the
+    /// 512-bit product as two 256-bit limbs `(prod1, prod0)` via `mulmod`,
+    /// then either take the cheap path (`prod1 == 0`) or peel off the
+    /// remainder, factor the largest power of two out of `denom`, and
+    /// finish with a Newton-Hensel modular inverse of the (now odd) `denom`.
+    pub fn mul_div(a: U256, b: U256, denom: U256) -> U256 {
+        match checked_mul_div(a, b, denom) {
+            Some(result) => result,
+            None if denom == U256::ZERO => panic!("Division by zero"),
+            None => panic!("mul_div overflow"),
+        }
+    }
+
+    /// Non-panicking twin of `mul_div`: `None` on division by zero or when
+    /// the quotient doesn't fit in 256 bits, instead of panicking.
+    pub fn checked_mul_div(a: U256, b: U256, denom: U256) -> Option<U256> {
+        if denom == U256::ZERO {
+            return None;
+        }
+
+        let prod0 = a.wrapping_mul(b);
+        let mm = a.mul_mod(b, U256::MAX);
+        let mut prod1 = mm.wrapping_sub(prod0);
+        if mm < prod0 {
+            prod1 = prod1.wrapping_sub(U256::from(1u64));
+        }
+
+        if prod1 == U256::ZERO {
+            return Some(prod0 / denom);
+        }
+
+        // The result must fit in 256 bits, i.e. denom must exceed the high limb.
+        if denom <= prod1 {
+            return None;
+        }
+
+        let remainder = a.mul_mod(b, denom);
+        if remainder > prod0 {
+            prod1 = prod1.wrapping_sub(U256::from(1u64));
+        }
+        let prod0 = prod0.wrapping_sub(remainder);
+
+        // Factor powers of two out of denom so it becomes odd.
+        let twos = denom.wrapping_neg() & denom;
+        let denom = denom / twos;
+        let prod0 = prod0 / twos;
+        let twos_inv = (twos.wrapping_neg() / twos).wrapping_add(U256::from(1u64));
+        let prod0 = prod0 | prod1.wrapping_mul(twos_inv);
+
+        // Modular inverse of the odd `denom` mod 2^256 via Newton-Hensel iteration.
+        let mut inv = (U256::from(3u64).wrapping_mul(denom)) ^ U256::from(2u64);
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(U256::from(2u64).wrapping_sub(denom.wrapping_mul(inv)));
+        }
+
+        Some(prod0.wrapping_mul(inv))
+    }
+
     /// Multiply two fixed-point numbers
-    /// (a * b) / SCALE
+    /// (a * b) / SCALE, computed at full precision via `mul_div`
     pub fn mul_fixed(a: U256, b: U256) -> U256 {
-        let product = a * b;
-        product / SCALE_U256
+        mul_div(a, b, SCALE_U256)
     }
 
     /// Divide two fixed-point numbers
-    /// (a * SCALE) / b
+    /// (a * SCALE) / b, computed at full precision via `mul_div`
     pub fn div_fixed(a: U256, b: U256) -> U256 {
         if b == U256::ZERO {
             panic!("Division by zero");
         }
-        let scaled_a = a * SCALE_U256;
-        scaled_a / b
+        mul_div(a, SCALE_U256, b)
     }
 
     /// Square a fixed-point number
@@ -45,6 +105,30 @@ mod fixed_point {
         mul_fixed(a, a)
     }
 
+    /// Fixed-point square root via Newton's method: `y_{n+1} = (y_n + x*SCALE/y_n) / 2`,
+    /// seeded from the bit-length of `x` so it converges in under 10 steps.
+    pub fn sqrt_fixed(x: U256) -> U256 {
+        if x == U256::ZERO {
+            return U256::ZERO;
+        }
+
+        // The fixed-point result is ~sqrt(x * SCALE); estimate its bit-length
+        // as bit_len(x) + bit_len(SCALE) - 1 to seed without overflowing by
+        // actually computing x * SCALE.
+        let seed_bits = x.bit_len() + SCALE_U256.bit_len() - 1;
+        let mut y = U256::from(1u64) << (seed_bits / 2).max(1);
+
+        for _ in 0..10 {
+            let y_next = (y + mul_div(x, SCALE_U256, y)) / U256::from(2u64);
+            if y_next == y {
+                break;
+            }
+            y = y_next;
+        }
+
+        y
+    }
+
     /// Convert fixed-point to percentage (basis points)
     /// e.g., 0.15 * 10^18 -> 1500 (15%)
     pub fn to_basis_points(a: U256) -> U256 {
@@ -52,9 +136,156 @@ mod fixed_point {
     }
 }
 
+// Signed fixed-point module
+//
+// Replaces the `(U256, bool)` magnitude/sign pairs that used to get threaded
+// through every sigmoid/exp function with a single value type, modeled on
+// Substrate's `Fixed128`: 18-decimal scale, checked arithmetic that panics
+// on overflow/underflow (matching `fixed_point::div_fixed`'s own panic on
+// division by zero).
+mod signed_fixed {
+    use super::fixed_point;
+    use fluentbase_sdk::U256;
+
+    /// An 18-decimal fixed-point number with an explicit sign.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SignedFixed {
+        magnitude: U256,
+        negative: bool,
+    }
+
+    impl SignedFixed {
+        pub const ZERO: SignedFixed = SignedFixed {
+            magnitude: U256::ZERO,
+            negative: false,
+        };
+
+        /// Build a `SignedFixed`, normalizing a zero magnitude to non-negative.
+        pub fn new(magnitude: U256, negative: bool) -> Self {
+            SignedFixed {
+                magnitude,
+                negative: negative && magnitude != U256::ZERO,
+            }
+        }
+
+        /// Fixed-point representation of a non-negative decimal, e.g.
+        /// `from_decimal(1) == 1.0`.
+        pub fn from_decimal(value: u128) -> Self {
+            SignedFixed::new(fixed_point::from_decimal(value), false)
+        }
+
+        pub fn from_unsigned(magnitude: U256) -> Self {
+            SignedFixed::new(magnitude, false)
+        }
+
+        pub fn magnitude(&self) -> U256 {
+            self.magnitude
+        }
+
+        pub fn is_negative(&self) -> bool {
+            self.negative
+        }
+
+        pub fn abs(&self) -> SignedFixed {
+            SignedFixed::new(self.magnitude, false)
+        }
+
+        pub fn neg(&self) -> SignedFixed {
+            SignedFixed::new(self.magnitude, !self.negative)
+        }
+
+        pub fn add(&self, other: SignedFixed) -> SignedFixed {
+            match (self.negative, other.negative) {
+                (false, false) => SignedFixed::new(self.magnitude + other.magnitude, false),
+                (true, true) => SignedFixed::new(self.magnitude + other.magnitude, true),
+                (false, true) => {
+                    if self.magnitude >= other.magnitude {
+                        SignedFixed::new(self.magnitude - other.magnitude, false)
+                    } else {
+                        SignedFixed::new(other.magnitude - self.magnitude, true)
+                    }
+                }
+                (true, false) => {
+                    if self.magnitude >= other.magnitude {
+                        SignedFixed::new(self.magnitude - other.magnitude, true)
+                    } else {
+                        SignedFixed::new(other.magnitude - self.magnitude, false)
+                    }
+                }
+            }
+        }
+
+        pub fn sub(&self, other: SignedFixed) -> SignedFixed {
+            self.add(other.neg())
+        }
+
+        pub fn mul(&self, other: SignedFixed) -> SignedFixed {
+            let magnitude = fixed_point::mul_fixed(self.magnitude, other.magnitude);
+            SignedFixed::new(magnitude, self.negative != other.negative)
+        }
+
+        pub fn div(&self, other: SignedFixed) -> SignedFixed {
+            if other.magnitude == U256::ZERO {
+                panic!("Division by zero");
+            }
+            let magnitude = fixed_point::div_fixed(self.magnitude, other.magnitude);
+            SignedFixed::new(magnitude, self.negative != other.negative)
+        }
+
+        /// Like `add`, but clamps the magnitude to `U256::MAX` instead of
+        /// panicking when both operands share a sign and overflow.
+        pub fn saturating_add(&self, other: SignedFixed) -> SignedFixed {
+            match (self.negative, other.negative) {
+                (false, false) => {
+                    SignedFixed::new(self.magnitude.saturating_add(other.magnitude), false)
+                }
+                (true, true) => {
+                    SignedFixed::new(self.magnitude.saturating_add(other.magnitude), true)
+                }
+                // Opposite signs only ever shrink the magnitude, so this can't overflow.
+                _ => self.add(other),
+            }
+        }
+
+        /// Like `sub`, but clamps the magnitude to `U256::MAX` instead of panicking.
+        pub fn saturating_sub(&self, other: SignedFixed) -> SignedFixed {
+            self.saturating_add(other.neg())
+        }
+
+        /// Like `mul`, but clamps the magnitude to `U256::MAX` instead of
+        /// panicking when the true product doesn't fit in 256 bits.
+        pub fn saturating_mul(&self, other: SignedFixed) -> SignedFixed {
+            let negative = self.negative != other.negative;
+            match fixed_point::checked_mul_div(
+                self.magnitude,
+                other.magnitude,
+                fixed_point::SCALE_U256,
+            ) {
+                Some(magnitude) => SignedFixed::new(magnitude, negative),
+                None => SignedFixed::new(U256::MAX, negative),
+            }
+        }
+
+        /// Like `div`, but clamps to `U256::MAX` instead of panicking, both
+        /// on division by zero and when the true quotient overflows.
+        pub fn saturating_div(&self, other: SignedFixed) -> SignedFixed {
+            let negative = self.negative != other.negative;
+            match fixed_point::checked_mul_div(
+                self.magnitude,
+                fixed_point::SCALE_U256,
+                other.magnitude,
+            ) {
+                Some(magnitude) => SignedFixed::new(magnitude, negative),
+                None => SignedFixed::new(U256::MAX, negative),
+            }
+        }
+    }
+}
+
 // Sigmoid math module
 mod sigmoid_math {
     use super::fixed_point::*;
+    use super::signed_fixed::SignedFixed;
     use fluentbase_sdk::U256;
     use libm;
 
@@ -133,6 +364,66 @@ mod sigmoid_math {
         }
     }
 
+    /// ln(2) in 18-decimal fixed point (0.693147180559945309...)
+    const LN2_FIXED: u128 = 693_147_180_559_945_309;
+
+    /// Largest `n` we'll realize `2^n` for via repeated doubling before
+    /// returning `exp_overflow_sentinel()` instead.
+    const MAX_EXP_DOUBLINGS: u64 = 128;
+
+    fn ln2_fixed() -> U256 {
+        U256::from(LN2_FIXED)
+    }
+
+    /// Sentinel returned when a result would overflow `U256` (mirrors the
+    /// large-value sentinel already used by `exp_precise`).
+    fn exp_overflow_sentinel() -> U256 {
+        U256::from(u128::MAX) * SCALE_U256 / U256::from(1000u128)
+    }
+
+    /// Deterministic, pure-integer `e^x`. Unlike `exp_precise` this never
+    /// touches `f64`, so it produces bit-identical results across WASM
+    /// runtimes and the native test target.
+    ///
+    /// Uses range reduction: `n = round(x / ln2)`, `r = x - n*ln2` so that
+    /// `|r| < ln2/2`, then `e^x = 2^n * e^r`. `e^r` converges in a handful of
+    /// terms via the existing Taylor series; `2^n` is realized by repeated
+    /// fixed-point doubling.
+    pub fn exp_fixed(x: U256, is_negative: bool) -> U256 {
+        if is_negative {
+            let positive = exp_fixed(x, false);
+            return div_fixed(SCALE_U256, positive);
+        }
+
+        let ln2 = ln2_fixed();
+        // n = round(x / ln2), integer (not fixed-point scaled: the SCALE
+        // factor on x and ln2 cancels out of the ratio).
+        let n_u256 = (x + ln2 / U256::from(2u64)) / ln2;
+        if n_u256 > U256::from(MAX_EXP_DOUBLINGS) {
+            return exp_overflow_sentinel();
+        }
+        let n = n_u256.as_limbs()[0];
+
+        let n_times_ln2 = U256::from(n) * ln2;
+        let (r, r_negative) = if x >= n_times_ln2 {
+            (x - n_times_ln2, false)
+        } else {
+            (n_times_ln2 - x, true)
+        };
+
+        // |r| < ln2/2, so the existing Taylor loop converges in ~6 terms.
+        let mut result = exp_taylor(r, r_negative);
+
+        for _ in 0..n {
+            match result.checked_mul(U256::from(2u64)) {
+                Some(doubled) => result = doubled,
+                None => return exp_overflow_sentinel(),
+            }
+        }
+
+        result
+    }
+
     /// High-precision sigmoid using libm
     pub fn sigmoid_precise(x: U256, is_negative: bool) -> U256 {
         if is_negative {
@@ -146,13 +437,33 @@ mod sigmoid_math {
         }
     }
 
+    /// Deterministic sigmoid built on `exp_fixed` (no `f64`).
+    pub fn sigmoid_deterministic(x: U256, is_negative: bool) -> U256 {
+        if is_negative {
+            // For negative x: e^(-|x|) / (1 + e^(-|x|))
+            let exp_neg_x = exp_fixed(x, true);
+            div_fixed(exp_neg_x, SCALE_U256 + exp_neg_x)
+        } else {
+            // For positive x: 1 / (1 + e^(-x))
+            let exp_neg_x = exp_fixed(x, true);
+            div_fixed(SCALE_U256, SCALE_U256 + exp_neg_x)
+        }
+    }
+
     /// Calculate sigmoid function: 1 / (1 + e^(-x))
     /// For numerical stability, we use:
     /// - If x >= 0: 1 / (1 + e^(-x))
     /// - If x < 0: e^x / (1 + e^x)
+    pub fn sigmoid(x: SignedFixed) -> U256 {
+        // Use the deterministic, pure-integer exponential by default so
+        // on-chain pricing is bit-reproducible across WASM runtimes;
+        // `sigmoid_precise` remains available as an opt-in libm-backed path.
+        sigmoid_deterministic(x.magnitude(), x.is_negative())
+    }
+
+    /// Solidity-ABI shim: older callers still pass a magnitude/sign pair.
     pub fn sigmoid_fixed(x: U256, is_negative: bool) -> U256 {
-        // Use precise version with libm
-        sigmoid_precise(x, is_negative)
+        sigmoid(SignedFixed::new(x, is_negative))
     }
 
     /// Calculate the price using sigmoid bonding curve
@@ -163,48 +474,318 @@ mod sigmoid_math {
         k: U256, // Steepness (with decimals)
         b: U256, // Inflection point (with decimals)
     ) -> U256 {
-        // Calculate (supply - B)
-        let (diff, is_negative) = if supply >= b {
-            (supply - b, false)
-        } else {
-            (b - supply, true)
-        };
+        // (supply - B) as a single signed subtraction, no paired-argument bookkeeping
+        let diff = SignedFixed::from_unsigned(supply).sub(SignedFixed::from_unsigned(b));
+
+        // k * (supply - B)
+        let k_times_diff = diff.mul(SignedFixed::from_unsigned(k));
+
+        // A * sigmoid(k * (supply - B))
+        mul_fixed(a, sigmoid(k_times_diff))
+    }
 
-        // Calculate k * (supply - B)
-        let k_times_diff = mul_fixed(k, diff);
+    /// Maximum iterations for the `ln` atanh series (kept small since the
+    /// series input is normalized to [-1/3, 1/3] and converges fast)
+    const MAX_LN_ITERATIONS: usize = 10;
+
+    /// Fixed-point natural log, returned as `(magnitude, is_negative)` since
+    /// `ln` of a sub-1.0 fixed-point value is negative.
+    ///
+    /// Factors `x = m * 2^e` with `m` normalized into `[1, 2)` via the
+    /// bit-length of `x`'s integer part, so `ln(x) = e*ln2 + ln(m)`, then
+    /// evaluates `ln(m)` with the fast-converging atanh series
+    /// `ln(m) = 2*(t + t^3/3 + t^5/5 + ...)` where `t = (m-1)/(m+1)`.
+    pub fn ln_fixed(x: U256) -> (U256, bool) {
+        if x == U256::ZERO {
+            panic!("ln of zero");
+        }
+        if x == SCALE_U256 {
+            return (U256::ZERO, false);
+        }
+        if x < SCALE_U256 {
+            // ln(x) = -ln(1/x) for x in (0, 1)
+            let recip = div_fixed(SCALE_U256, x);
+            let (magnitude, _) = ln_fixed(recip);
+            return (magnitude, true);
+        }
+
+        let int_part = x / SCALE_U256;
+        let e = (int_part.bit_len() - 1) as u32;
+        let two_pow_e = U256::from(1u64) << e;
+        let m = div_fixed(x, two_pow_e * SCALE_U256);
+
+        let t = div_fixed(m - SCALE_U256, m + SCALE_U256);
+        let t_sq = mul_fixed(t, t);
+
+        let mut term = t;
+        let mut sum = t;
+        let mut n = 1u64;
+        for _ in 0..MAX_LN_ITERATIONS {
+            term = mul_fixed(term, t_sq);
+            n += 2;
+            sum = sum + term / U256::from(n);
+            if term < U256::from(1000u64) {
+                break;
+            }
+        }
+        let ln_m = U256::from(2u64) * sum;
 
-        // Calculate sigmoid of k * (supply - B)
-        let sigmoid = sigmoid_fixed(k_times_diff, is_negative);
+        (U256::from(e) * ln2_fixed() + ln_m, false)
+    }
 
-        // Return A * sigmoid
-        mul_fixed(a, sigmoid)
+    /// `ln(1 + e^k_diff)` where `k_diff` carries its own sign, as needed by
+    /// the sigmoid integral's antiderivative. Falls back to the linear
+    /// asymptote `ln(1 + e^y) ~= y` when `exp_fixed` returns its overflow
+    /// sentinel (or more), since the sentinel no longer stands for `e^y`.
+    fn ln_one_plus_exp(k_diff: U256, k_diff_negative: bool) -> U256 {
+        let exp_val = exp_fixed(k_diff, k_diff_negative);
+        if !k_diff_negative && exp_val >= exp_overflow_sentinel() {
+            return k_diff;
+        }
+        let (ln_val, _) = ln_fixed(SCALE_U256 + exp_val);
+        ln_val
     }
 
     /// Calculate the integral of the sigmoid function (for exact buy/sell amounts)
     /// This is where Rust really shines vs Solidity
+    ///
+    /// Uses the closed-form antiderivative instead of numerical integration:
+    /// integral of A/(1 + e^(-k*(x-B))) dx = (A/k) * ln(1 + e^(k*(x-B)))
+    /// so the exact cost between two supply points is a constant-time
+    /// evaluation of that antiderivative at both endpoints.
     pub fn sigmoid_integral(from_supply: U256, to_supply: U256, a: U256, k: U256, b: U256) -> U256 {
-        // The integral of A/(1 + e^(-k*(x-B))) is:
-        // (A/k) * ln(1 + e^(k*(x-B))) + C
-
-        // For now, use numerical integration with small steps
-        // In production, we'd use the analytical solution with libm::log
-        let steps = 100u64;
-        let step_size = if to_supply > from_supply {
-            (to_supply - from_supply) / U256::from(steps)
+        if to_supply <= from_supply {
+            return U256::ZERO;
+        }
+
+        let antiderivative_at = |supply: U256| -> U256 {
+            let (diff, diff_negative) = if supply >= b {
+                (supply - b, false)
+            } else {
+                (b - supply, true)
+            };
+            let k_diff = mul_fixed(k, diff);
+            ln_one_plus_exp(k_diff, diff_negative)
+        };
+
+        let ln_to = antiderivative_at(to_supply);
+        let ln_from = antiderivative_at(from_supply);
+
+        let a_over_k = div_fixed(a, k);
+        mul_fixed(a_over_k, ln_to - ln_from)
+    }
+
+    /// Maximum Newton-Raphson iterations before giving up on convergence.
+    const MAX_NEWTON_ITERATIONS: usize = 20;
+
+    fn newton_epsilon() -> U256 {
+        U256::from(1_000_000u64) // 1e-12 in 18-decimal fixed point
+    }
+
+    /// Invert `sigmoid_integral`: how many tokens (`delta`) can `payment` wei
+    /// buy starting from `current_supply`? Solves
+    /// `integral(current_supply, current_supply + delta) = payment` for
+    /// `delta` via Newton's method, whose derivative is exactly the spot
+    /// price `calculate_sigmoid_price(current_supply + delta, a, k, b)`.
+    pub fn tokens_for_payment(
+        current_supply: U256,
+        payment: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256 {
+        if payment == U256::ZERO {
+            return U256::ZERO;
+        }
+
+        // Seed with delta0 = payment / price(current_supply). Far below the
+        // inflection point the spot price can round to 0 in fixed point; in
+        // that case seed from the curve's maximum price `a` instead (the
+        // true price is always <= a, so this under-estimates delta0 rather
+        // than dividing by zero).
+        let price_now = calculate_sigmoid_price(current_supply, a, k, b);
+        let mut delta = if price_now == U256::ZERO {
+            div_fixed(payment, a)
         } else {
-            U256::ZERO
+            div_fixed(payment, price_now)
         };
 
-        let mut integral = U256::ZERO;
-        let mut current_supply = from_supply;
+        for _ in 0..MAX_NEWTON_ITERATIONS {
+            let f = sigmoid_integral(current_supply, current_supply + delta, a, k, b);
+            let price = calculate_sigmoid_price(current_supply + delta, a, k, b);
 
-        for _ in 0..steps {
-            let price = calculate_sigmoid_price(current_supply, a, k, b);
-            integral = integral + mul_fixed(price, step_size);
-            current_supply = current_supply + step_size;
+            // The derivative of the integral is the spot price, which can
+            // also round to 0 in fixed point at this evaluation point (same
+            // issue as the seed above). Fall back to the curve's max price
+            // `a` so the step stays bounded instead of dividing by zero.
+            let price_for_step = if price == U256::ZERO { a } else { price };
+
+            // delta_{i+1} = delta_i - (f(delta_i) - payment) / price(supply + delta_i)
+            let (step, step_negative) = if f >= payment {
+                (div_fixed(f - payment, price_for_step), false)
+            } else {
+                (div_fixed(payment - f, price_for_step), true)
+            };
+
+            let next_delta = if step_negative {
+                delta + step
+            } else if delta >= step {
+                delta - step
+            } else {
+                U256::ZERO
+            };
+
+            let update = if next_delta >= delta {
+                next_delta - delta
+            } else {
+                delta - next_delta
+            };
+            delta = next_delta;
+
+            if update < newton_epsilon() {
+                break;
+            }
         }
 
-        integral
+        // Round the final answer down so the contract never sells more
+        // tokens than `payment` actually covers. A single corrective Newton
+        // step (same f/price derivative) clears Newton's own overshoot in
+        // O(1) rather than scanning down one raw fixed-point unit at a time;
+        // the tightly-bounded loop below only mops up any residual left by
+        // ln/exp's own fixed-point rounding.
+        let f_final = sigmoid_integral(current_supply, current_supply + delta, a, k, b);
+        if f_final > payment {
+            let price_final = calculate_sigmoid_price(current_supply + delta, a, k, b);
+            if price_final > U256::ZERO {
+                let overshoot = f_final - payment;
+                let correction = div_fixed(overshoot, price_final) + U256::from(1u64);
+                delta = if delta >= correction {
+                    delta - correction
+                } else {
+                    U256::ZERO
+                };
+            }
+        }
+
+        let mut guard = 0u32;
+        while delta > U256::ZERO
+            && guard < 8
+            && sigmoid_integral(current_supply, current_supply + delta, a, k, b) > payment
+        {
+            delta = delta - U256::from(1u64);
+            guard += 1;
+        }
+
+        delta
+    }
+}
+
+// Generalized bonding-curve module: price/integral dispatch by curve family.
+// `calculate_price`/`calculate_integral` hardcode the sigmoid curve; this
+// module reuses the same fixed-point and root-finding primitives to offer
+// the other curve families as well, each with its own closed-form integral
+// so buy/sell quoting stays constant-time.
+mod curves {
+    use super::fixed_point::*;
+    use super::sigmoid_math;
+    use fluentbase_sdk::U256;
+
+    /// Which bonding-curve family to price/integrate. Encoded as a small
+    /// integer over the ABI boundary (see `from_u256`) since the router
+    /// only understands primitive Solidity types.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CurveMode {
+        Sigmoid,
+        Linear,
+        Exponential,
+        SquareRoot,
+    }
+
+    impl CurveMode {
+        /// 0 = Sigmoid, 1 = Linear, 2 = Exponential, 3 = SquareRoot
+        pub fn from_u256(mode: U256) -> Self {
+            // Compare against the full 256-bit value rather than truncating
+            // to the low limb, so any selector outside 0..=3 (e.g. 2^64,
+            // which aliases to 0 in the low limb alone) is rejected.
+            if mode == U256::from(0u64) {
+                CurveMode::Sigmoid
+            } else if mode == U256::from(1u64) {
+                CurveMode::Linear
+            } else if mode == U256::from(2u64) {
+                CurveMode::Exponential
+            } else if mode == U256::from(3u64) {
+                CurveMode::SquareRoot
+            } else {
+                panic!("Unknown curve mode")
+            }
+        }
+    }
+
+    /// Parameters shared across curve families; each curve only uses the
+    /// subset relevant to its formula (documented per variant in `price`/`integral`).
+    pub struct CurveParams {
+        pub m: U256, // Linear slope / SquareRoot coefficient
+        pub c: U256, // Linear intercept
+        pub a: U256, // Sigmoid max price / Exponential scale
+        pub k: U256, // Sigmoid steepness / Exponential rate
+        pub b: U256, // Sigmoid inflection point
+    }
+
+    /// Spot price at `supply` for the given curve family.
+    pub fn price(mode: CurveMode, supply: U256, params: &CurveParams) -> U256 {
+        match mode {
+            CurveMode::Sigmoid => {
+                sigmoid_math::calculate_sigmoid_price(supply, params.a, params.k, params.b)
+            }
+            // price = m*supply + c
+            CurveMode::Linear => mul_fixed(params.m, supply) + params.c,
+            // price = a * e^(k*supply)
+            CurveMode::Exponential => {
+                let exponent = mul_fixed(params.k, supply);
+                mul_fixed(params.a, sigmoid_math::exp_fixed(exponent, false))
+            }
+            // price = m*sqrt(supply)
+            CurveMode::SquareRoot => mul_fixed(params.m, sqrt_fixed(supply)),
+        }
+    }
+
+    /// Exact integral of `price` between `from_supply` and `to_supply`.
+    pub fn integral(
+        mode: CurveMode,
+        from_supply: U256,
+        to_supply: U256,
+        params: &CurveParams,
+    ) -> U256 {
+        if to_supply <= from_supply {
+            return U256::ZERO;
+        }
+
+        match mode {
+            CurveMode::Sigmoid => {
+                sigmoid_math::sigmoid_integral(from_supply, to_supply, params.a, params.k, params.b)
+            }
+            // integral of (m*x + c) dx = (m/2)*(to^2 - from^2) + c*(to - from)
+            CurveMode::Linear => {
+                let to_sq = square_fixed(to_supply);
+                let from_sq = square_fixed(from_supply);
+                let quadratic_term = mul_fixed(params.m, to_sq - from_sq) / U256::from(2u64);
+                let linear_term = mul_fixed(params.c, to_supply - from_supply);
+                quadratic_term + linear_term
+            }
+            // integral of a*e^(k*x) dx = (a/k) * (e^(k*to) - e^(k*from))
+            CurveMode::Exponential => {
+                let exp_to = sigmoid_math::exp_fixed(mul_fixed(params.k, to_supply), false);
+                let exp_from = sigmoid_math::exp_fixed(mul_fixed(params.k, from_supply), false);
+                let a_over_k = div_fixed(params.a, params.k);
+                mul_fixed(a_over_k, exp_to - exp_from)
+            }
+            // integral of m*sqrt(x) dx = (2/3)*m*x^(3/2)
+            CurveMode::SquareRoot => {
+                let power_1_5 = |x: U256| mul_fixed(x, sqrt_fixed(x));
+                let diff = power_1_5(to_supply) - power_1_5(from_supply);
+                (U256::from(2u64) * mul_fixed(params.m, diff)) / U256::from(3u64)
+            }
+        }
     }
 }
 
@@ -224,12 +805,15 @@ pub trait SigmoidAPI {
     // Fixed-point math functions
     fn mul_fixed(&self, a: U256, b: U256) -> U256;
     fn div_fixed(&self, a: U256, b: U256) -> U256;
+    fn mul_div(&self, a: U256, b: U256, denom: U256) -> U256;
     fn square_fixed(&self, a: U256) -> U256;
+    fn sqrt_fixed(&self, x: U256) -> U256;
     fn scale(&self) -> U256;
 
     // Sigmoid functions
     fn exp_taylor(&self, x: U256, is_negative: bool) -> U256;
     fn exp_precise(&self, x: U256, is_negative: bool) -> U256;
+    fn exp_fixed(&self, x: U256, is_negative: bool) -> U256;
     fn sigmoid(&self, x: U256, is_negative: bool) -> U256;
     fn calculate_price(&self, supply: U256, a: U256, k: U256, b: U256) -> U256;
     fn calculate_integral(
@@ -240,6 +824,39 @@ pub trait SigmoidAPI {
         k: U256,
         b: U256,
     ) -> U256;
+    fn tokens_for_payment(
+        &self,
+        current_supply: U256,
+        payment: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256;
+
+    // Generalized bonding-curve dispatch. `mode` selects the curve family:
+    // 0 = Sigmoid, 1 = Linear, 2 = Exponential, 3 = SquareRoot. Each curve
+    // only reads the params relevant to its formula (see `curves` module).
+    fn price_with_curve(
+        &self,
+        mode: U256,
+        supply: U256,
+        m: U256,
+        c: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256;
+    fn integral_with_curve(
+        &self,
+        mode: U256,
+        from_supply: U256,
+        to_supply: U256,
+        m: U256,
+        c: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256;
 }
 
 // Implement the router for automatic function dispatch
@@ -252,7 +869,7 @@ impl<SDK: SharedAPI> SigmoidAPI for SigmoidCalculator<SDK> {
 
     /// Returns the contract version
     fn get_version(&self) -> U256 {
-        U256::from(2) // Version 2: with libm integration
+        U256::from(5) // Version 5: generalized bonding-curve modes (Linear/Exponential/SquareRoot)
     }
 
     /// Echoes back the input value (tests parameter passing)
@@ -275,11 +892,21 @@ impl<SDK: SharedAPI> SigmoidAPI for SigmoidCalculator<SDK> {
         fixed_point::div_fixed(a, b)
     }
 
+    /// Compute floor(a * b / denom) at full 512-bit precision
+    fn mul_div(&self, a: U256, b: U256, denom: U256) -> U256 {
+        fixed_point::mul_div(a, b, denom)
+    }
+
     /// Square a fixed-point number
     fn square_fixed(&self, a: U256) -> U256 {
         fixed_point::square_fixed(a)
     }
 
+    /// Fixed-point square root
+    fn sqrt_fixed(&self, x: U256) -> U256 {
+        fixed_point::sqrt_fixed(x)
+    }
+
     /// Get the scale factor (10^18)
     fn scale(&self) -> U256 {
         fixed_point::SCALE_U256
@@ -295,6 +922,11 @@ impl<SDK: SharedAPI> SigmoidAPI for SigmoidCalculator<SDK> {
         sigmoid_math::exp_precise(x, is_negative)
     }
 
+    /// Deterministic, pure-integer exponential (no f64, bit-reproducible)
+    fn exp_fixed(&self, x: U256, is_negative: bool) -> U256 {
+        sigmoid_math::exp_fixed(x, is_negative)
+    }
+
     /// Sigmoid function: 1 / (1 + e^(-x))
     fn sigmoid(&self, x: U256, is_negative: bool) -> U256 {
         sigmoid_math::sigmoid_fixed(x, is_negative)
@@ -316,6 +948,54 @@ impl<SDK: SharedAPI> SigmoidAPI for SigmoidCalculator<SDK> {
     ) -> U256 {
         sigmoid_math::sigmoid_integral(from_supply, to_supply, a, k, b)
     }
+
+    /// How many tokens `payment` wei buys from `current_supply`, via Newton's method
+    fn tokens_for_payment(
+        &self,
+        current_supply: U256,
+        payment: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256 {
+        sigmoid_math::tokens_for_payment(current_supply, payment, a, k, b)
+    }
+
+    /// Spot price under a selectable curve family (see `curves::CurveMode`)
+    fn price_with_curve(
+        &self,
+        mode: U256,
+        supply: U256,
+        m: U256,
+        c: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256 {
+        let params = curves::CurveParams { m, c, a, k, b };
+        curves::price(curves::CurveMode::from_u256(mode), supply, &params)
+    }
+
+    /// Exact integral under a selectable curve family (see `curves::CurveMode`)
+    fn integral_with_curve(
+        &self,
+        mode: U256,
+        from_supply: U256,
+        to_supply: U256,
+        m: U256,
+        c: U256,
+        a: U256,
+        k: U256,
+        b: U256,
+    ) -> U256 {
+        let params = curves::CurveParams { m, c, a, k, b };
+        curves::integral(
+            curves::CurveMode::from_u256(mode),
+            from_supply,
+            to_supply,
+            &params,
+        )
+    }
 }
 
 // Contract implementation
@@ -334,8 +1014,10 @@ basic_entrypoint!(SigmoidCalculator);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use curves::{CurveMode, CurveParams};
     use fixed_point::*;
     use sigmoid_math::*;
+    use signed_fixed::SignedFixed;
 
     #[test]
     fn test_basic_arithmetic() {
@@ -372,6 +1054,31 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_mul_div_basic() {
+        // Test: (6 * 4) / 3 = 8, well within a single 256-bit limb
+        let result = fixed_point::mul_div(U256::from(6u64), U256::from(4u64), U256::from(3u64));
+        assert_eq!(result, U256::from(8u64));
+    }
+
+    #[test]
+    fn test_mul_div_matches_mul_fixed() {
+        // mul_fixed(a, b) is defined as mul_div(a, b, SCALE)
+        let a = U256::from(15u64) * SCALE_U256 / U256::from(10u64); // 1.5
+        let b = U256::from(2u64) * SCALE_U256; // 2.0
+        assert_eq!(mul_fixed(a, b), fixed_point::mul_div(a, b, SCALE_U256));
+    }
+
+    #[test]
+    fn test_mul_div_no_overflow_for_near_max_operands() {
+        // a * b here vastly exceeds U256::MAX as a plain product (the old
+        // `(a * b) / denom` implementation would panic); mul_div(a, b, b)
+        // must still recover the exact identity `a` via the 512-bit path.
+        let a = U256::MAX / U256::from(2u64);
+        let b = U256::MAX / U256::from(3u64);
+        assert_eq!(fixed_point::mul_div(a, b, b), a);
+    }
+
     #[test]
     fn test_square_fixed() {
         // Test: 3^2 = 9
@@ -411,6 +1118,334 @@ mod tests {
         assert!(result > expected_min && result < expected_max);
     }
 
+    #[test]
+    fn test_exp_fixed_zero() {
+        // e^0 = 1
+        let result = exp_fixed(U256::ZERO, false);
+        assert_eq!(result, SCALE_U256);
+    }
+
+    #[test]
+    fn test_exp_fixed_matches_taylor_small_range() {
+        // Within the Taylor series' comfortable range, exp_fixed's range
+        // reduction should agree closely with the direct Taylor expansion.
+        let one = SCALE_U256;
+        let taylor = exp_taylor(one, false);
+        let fixed = exp_fixed(one, false);
+        let diff = if taylor > fixed {
+            taylor - fixed
+        } else {
+            fixed - taylor
+        };
+        assert!(diff < SCALE_U256 / U256::from(1000u64));
+    }
+
+    #[test]
+    fn test_exp_fixed_large_value_no_panic() {
+        // e^50 is far outside exp_taylor's range (it clamps its input at 5);
+        // exp_fixed must still return a large result instead of
+        // overflowing or panicking.
+        let fifty = U256::from(50u64) * SCALE_U256;
+        let result = exp_fixed(fifty, false);
+        assert!(result > SCALE_U256);
+    }
+
+    #[test]
+    fn test_exp_fixed_negative_is_reciprocal() {
+        let one = SCALE_U256;
+        let pos = exp_fixed(one, false);
+        let neg = exp_fixed(one, true);
+        // e^1 * e^-1 ~= 1
+        let product = mul_fixed(pos, neg);
+        let diff = if product > SCALE_U256 {
+            product - SCALE_U256
+        } else {
+            SCALE_U256 - product
+        };
+        assert!(diff < SCALE_U256 / U256::from(1000u64));
+    }
+
+    #[test]
+    fn test_ln_fixed_of_one_is_zero() {
+        let (magnitude, negative) = ln_fixed(SCALE_U256);
+        assert_eq!(magnitude, U256::ZERO);
+        assert!(!negative);
+    }
+
+    #[test]
+    fn test_ln_fixed_of_e_is_one() {
+        // ln(e) = 1; reuse exp_fixed(1) as a precise stand-in for e.
+        let e = exp_fixed(SCALE_U256, false);
+        let (magnitude, negative) = ln_fixed(e);
+        assert!(!negative);
+        let diff = if magnitude > SCALE_U256 {
+            magnitude - SCALE_U256
+        } else {
+            SCALE_U256 - magnitude
+        };
+        assert!(diff < SCALE_U256 / U256::from(1000u64));
+    }
+
+    #[test]
+    fn test_ln_fixed_below_one_is_negative() {
+        // ln(0.5) ≈ -0.6931
+        let half = SCALE_U256 / U256::from(2u64);
+        let (magnitude, negative) = ln_fixed(half);
+        assert!(negative);
+        let expected_min = U256::from(69u64) * SCALE_U256 / U256::from(100u64);
+        let expected_max = U256::from(70u64) * SCALE_U256 / U256::from(100u64);
+        assert!(magnitude > expected_min && magnitude < expected_max);
+    }
+
+    #[test]
+    fn test_sigmoid_integral_closed_form_matches_midpoint_sum() {
+        // Cross-check the closed-form antiderivative against a fine-grained
+        // numerical midpoint sum over the same range. A left-endpoint sum
+        // systematically underestimates an increasing integrand (the error
+        // doesn't average out), so sample each slice at its midpoint instead.
+        let a = from_decimal(10);
+        let k = from_decimal(1);
+        let b = from_decimal(5);
+        let from_supply = from_decimal(3);
+        let to_supply = from_decimal(7);
+
+        let closed_form = sigmoid_integral(from_supply, to_supply, a, k, b);
+
+        let steps = 1000u64;
+        let step_size = (to_supply - from_supply) / U256::from(steps);
+        let half_step = step_size / U256::from(2u64);
+        let mut numerical = U256::ZERO;
+        let mut current_supply = from_supply;
+        for _ in 0..steps {
+            let price = calculate_sigmoid_price(current_supply + half_step, a, k, b);
+            numerical = numerical + mul_fixed(price, step_size);
+            current_supply = current_supply + step_size;
+        }
+
+        let diff = if closed_form > numerical {
+            closed_form - numerical
+        } else {
+            numerical - closed_form
+        };
+        // Allow a small tolerance for the numerical sum's own discretization error.
+        let tolerance = from_decimal(1) / U256::from(100u64);
+        assert!(diff < tolerance);
+    }
+
+    #[test]
+    fn test_sigmoid_integral_empty_range_is_zero() {
+        let a = from_decimal(10);
+        let k = from_decimal(1);
+        let b = from_decimal(5);
+        let supply = from_decimal(5);
+        assert_eq!(sigmoid_integral(supply, supply, a, k, b), U256::ZERO);
+    }
+
+    #[test]
+    fn test_signed_fixed_sub_crossing_zero() {
+        // 3.0 - 5.0 = -2.0
+        let three = SignedFixed::from_decimal(3);
+        let five = SignedFixed::from_decimal(5);
+        let result = three.sub(five);
+        assert!(result.is_negative());
+        assert_eq!(result.magnitude(), from_decimal(2));
+    }
+
+    #[test]
+    fn test_signed_fixed_mul_sign_rules() {
+        let two = SignedFixed::from_decimal(2);
+        let neg_three = SignedFixed::new(from_decimal(3), true);
+        let result = two.mul(neg_three);
+        assert!(result.is_negative());
+        assert_eq!(result.magnitude(), from_decimal(6));
+
+        let result = neg_three.mul(neg_three);
+        assert!(!result.is_negative());
+        assert_eq!(result.magnitude(), from_decimal(9));
+    }
+
+    #[test]
+    fn test_signed_fixed_saturating_add_clamps_instead_of_panicking() {
+        let near_max = SignedFixed::new(U256::MAX - from_decimal(1), false);
+        let result = near_max.saturating_add(SignedFixed::from_decimal(5));
+        assert!(!result.is_negative());
+        assert_eq!(result.magnitude(), U256::MAX);
+    }
+
+    #[test]
+    fn test_signed_fixed_saturating_sub_clamps_on_negative_overflow() {
+        let near_max_negative = SignedFixed::new(U256::MAX - from_decimal(1), true);
+        let result = near_max_negative.saturating_sub(SignedFixed::from_decimal(5));
+        assert!(result.is_negative());
+        assert_eq!(result.magnitude(), U256::MAX);
+    }
+
+    #[test]
+    fn test_signed_fixed_saturating_add_matches_add_when_no_overflow() {
+        let three = SignedFixed::from_decimal(3);
+        let five = SignedFixed::from_decimal(5);
+        assert_eq!(three.saturating_add(five), three.add(five));
+    }
+
+    #[test]
+    fn test_signed_fixed_saturating_div_clamps_on_division_by_zero() {
+        let one = SignedFixed::from_decimal(1);
+        let result = one.saturating_div(SignedFixed::ZERO);
+        assert!(!result.is_negative());
+        assert_eq!(result.magnitude(), U256::MAX);
+    }
+
+    #[test]
+    fn test_signed_fixed_calculate_sigmoid_price_matches_shim() {
+        // calculate_sigmoid_price now routes supply - B through SignedFixed
+        // internally; it should still agree with sigmoid_fixed's own shim.
+        let a = from_decimal(10);
+        let k = from_decimal(1);
+        let b = from_decimal(5);
+
+        let supply_below_b = from_decimal(3);
+        let price = calculate_sigmoid_price(supply_below_b, a, k, b);
+        let expected = mul_fixed(a, sigmoid_fixed(mul_fixed(k, from_decimal(2)), true));
+        assert_eq!(price, expected);
+    }
+
+    #[test]
+    fn test_tokens_for_payment_zero_payment() {
+        let a = from_decimal(10);
+        let k = from_decimal(1);
+        let b = from_decimal(5);
+        assert_eq!(
+            tokens_for_payment(from_decimal(5), U256::ZERO, a, k, b),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn test_tokens_for_payment_inverts_integral() {
+        // Buying the tokens Newton's method returns should cost no more than
+        // `payment`, and should spend nearly all of it.
+        let a = from_decimal(10);
+        let k = from_decimal(1);
+        let b = from_decimal(5);
+        let current_supply = from_decimal(3);
+        let payment = from_decimal(5);
+
+        let delta = tokens_for_payment(current_supply, payment, a, k, b);
+        let actual_cost = sigmoid_integral(current_supply, current_supply + delta, a, k, b);
+
+        assert!(actual_cost <= payment);
+        let shortfall = payment - actual_cost;
+        // Within a tiny fraction of a token's worth of payment of being exact.
+        assert!(shortfall < from_decimal(1) / U256::from(1000u64));
+    }
+
+    #[test]
+    fn test_tokens_for_payment_zero_spot_price_does_not_panic() {
+        // Far below the inflection point with a steep k, the spot price
+        // rounds to 0 in fixed point; tokens_for_payment must still seed
+        // from the max price `a` instead of panicking on a zero divisor.
+        let a = from_decimal(10);
+        let k = from_decimal(50);
+        let b = from_decimal(100);
+        let current_supply = U256::ZERO;
+        assert_eq!(calculate_sigmoid_price(current_supply, a, k, b), U256::ZERO);
+
+        let payment = from_decimal(1);
+        let delta = tokens_for_payment(current_supply, payment, a, k, b);
+        let actual_cost = sigmoid_integral(current_supply, current_supply + delta, a, k, b);
+        assert!(actual_cost <= payment);
+    }
+
+    #[test]
+    fn test_sqrt_fixed_perfect_square() {
+        // sqrt(9.0) = 3.0
+        let nine = U256::from(9u64) * SCALE_U256;
+        let result = sqrt_fixed(nine);
+        assert_eq!(result, U256::from(3u64) * SCALE_U256);
+    }
+
+    #[test]
+    fn test_sqrt_fixed_zero() {
+        assert_eq!(sqrt_fixed(U256::ZERO), U256::ZERO);
+    }
+
+    #[test]
+    fn test_sqrt_fixed_non_perfect_square() {
+        // sqrt(2.0) ≈ 1.41421356
+        let two = U256::from(2u64) * SCALE_U256;
+        let result = sqrt_fixed(two);
+        let expected_min = U256::from(141u64) * SCALE_U256 / U256::from(100u64);
+        let expected_max = U256::from(142u64) * SCALE_U256 / U256::from(100u64);
+        assert!(result > expected_min && result < expected_max);
+    }
+
+    #[test]
+    fn test_curve_linear_price_and_integral() {
+        // price = 2*supply + 1
+        let params = CurveParams {
+            m: from_decimal(2),
+            c: from_decimal(1),
+            a: U256::ZERO,
+            k: U256::ZERO,
+            b: U256::ZERO,
+        };
+        let price_at_3 = curves::price(CurveMode::Linear, from_decimal(3), &params);
+        assert_eq!(price_at_3, from_decimal(7));
+
+        // integral of (2x+1) dx from 0 to 3 = 3^2 + 3 = 12
+        let integral = curves::integral(CurveMode::Linear, U256::ZERO, from_decimal(3), &params);
+        assert_eq!(integral, from_decimal(12));
+    }
+
+    #[test]
+    fn test_curve_square_root_price() {
+        // price = 3*sqrt(supply); at supply=4, price = 3*2 = 6
+        let params = CurveParams {
+            m: from_decimal(3),
+            c: U256::ZERO,
+            a: U256::ZERO,
+            k: U256::ZERO,
+            b: U256::ZERO,
+        };
+        let price = curves::price(CurveMode::SquareRoot, from_decimal(4), &params);
+        let diff = if price > from_decimal(6) {
+            price - from_decimal(6)
+        } else {
+            from_decimal(6) - price
+        };
+        assert!(diff < SCALE_U256 / U256::from(1000u64));
+    }
+
+    #[test]
+    fn test_curve_sigmoid_matches_existing_calculate_price() {
+        let params = CurveParams {
+            m: U256::ZERO,
+            c: U256::ZERO,
+            a: from_decimal(10),
+            k: from_decimal(1),
+            b: from_decimal(5),
+        };
+        let supply = from_decimal(5);
+        let via_curves = curves::price(CurveMode::Sigmoid, supply, &params);
+        let via_direct = calculate_sigmoid_price(supply, params.a, params.k, params.b);
+        assert_eq!(via_curves, via_direct);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown curve mode")]
+    fn test_curve_mode_from_u256_rejects_unknown() {
+        CurveMode::from_u256(U256::from(99u64));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown curve mode")]
+    fn test_curve_mode_from_u256_rejects_high_bits_beyond_low_limb() {
+        // A mode whose low 64 bits alias a valid selector (0) but whose full
+        // value is out of range must still be rejected, not silently mapped
+        // to CurveMode::Sigmoid.
+        CurveMode::from_u256(U256::from(1u64) << 64);
+    }
+
     #[test]
     fn test_sigmoid_zero() {
         // Test sigmoid(0) = 0.5